@@ -23,25 +23,113 @@ use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue},
     Client, Method,
 };
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::Value;
-use std::str::FromStr;
+use std::{
+    collections::VecDeque,
+    str::FromStr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
 pub mod models;
 
+/// An OAuth2 app access token and the instant it expires at.
+struct CachedToken {
+    /// the bearer token
+    access_token: String,
+    /// when this token stops being valid
+    expires_at: Instant,
+}
+
+/// Response body from the `/oauth2/token` client-credentials flow.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    /// the bearer token
+    access_token: String,
+    /// seconds until the token expires
+    expires_in: u64,
+    /// always "bearer" for this grant type
+    #[allow(dead_code)]
+    token_type: String,
+}
+
+/// The result of a paginated query, along with Twitch's pagination metadata.
+#[derive(Debug)]
+pub struct Page<T> {
+    /// the items collected across however many pages were requested
+    pub items: Vec<T>,
+    /// the cursor to pass as "after" to fetch the next page, or `None` if
+    /// the result set is exhausted
+    pub cursor: Option<String>,
+    /// the total size of the result set, if the endpoint reports one
+    pub total: Option<i64>,
+}
+
+/// Application info used to build a `User-Agent` header, so that Twitch can
+/// identify which application is making requests.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use twitch_wrapper::AppInfo;
+/// let info = AppInfo {
+///     name: "my_app".to_owned(),
+///     version: Some("1.2.3".to_owned()),
+///     url: Some("https://example.com".to_owned()),
+/// };
+/// ```
+pub struct AppInfo {
+    /// the name of the calling application
+    pub name: String,
+    /// the version of the calling application
+    pub version: Option<String>,
+    /// a URL for the calling application
+    pub url: Option<String>,
+}
+
+impl AppInfo {
+    /// Format this info as a `User-Agent` string: `name/version (url)`,
+    /// degrading gracefully when `version` and/or `url` are missing.
+    fn to_user_agent(&self) -> String {
+        let mut value = self.name.clone();
+        if let Some(version) = &self.version {
+            value.push('/');
+            value.push_str(version);
+        }
+        if let Some(url) = &self.url {
+            value.push_str(&format!(" ({})", url));
+        }
+        value
+    }
+}
+
 /// Main API wrapper.
 ///
 /// Construct with `Twitch::new`, passing in your Client-ID from the developer console.
+/// If you also have a client secret, use `Twitch::with_secret` instead to get an
+/// app access token, which is now required by most Helix endpoints.
 pub struct Twitch {
     /// The reqwest HTTP client instance
     client: Client,
     /// The developer's client id from their Twitch developer apps
     client_id: String,
+    /// The developer's client secret, if using the client-credentials flow
+    client_secret: Option<String>,
+    /// The cached app access token, refreshed as needed
+    token: Mutex<Option<CachedToken>>,
+    /// Optional info about the calling application, sent as `User-Agent`
+    app_info: Option<AppInfo>,
 }
 
 impl Twitch {
     /// Construct a new instance of the Twitch struct in order to access the API.
     ///
+    /// This does not obtain an app access token, so requests will include the
+    /// `Client-Id` header but no `Authorization: Bearer` header, which most
+    /// Helix endpoints require; use `Twitch::with_secret` if you have a
+    /// client secret.
+    ///
     /// # Arguments
     ///
     /// * `client_id` - your client id from the [developer console]
@@ -51,9 +139,58 @@ impl Twitch {
         Twitch {
             client: Client::new(),
             client_id: client_id.to_owned(),
+            client_secret: None,
+            token: Mutex::new(None),
+            app_info: None,
         }
     }
 
+    /// Construct a new instance of the Twitch struct and obtain an app access
+    /// token via the OAuth2 client-credentials flow.
+    ///
+    /// The token is cached on the returned struct and automatically refreshed
+    /// once it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - your client id from the [developer console]
+    /// * `client_secret` - your client secret from the [developer console]
+    ///
+    /// [developer console]: https://dev.twitch.tv/console/apps
+    pub fn with_secret(client_id: &str, client_secret: &str) -> Result<Self> {
+        let twitch = Twitch {
+            client: Client::new(),
+            client_id: client_id.to_owned(),
+            client_secret: Some(client_secret.to_owned()),
+            token: Mutex::new(None),
+            app_info: None,
+        };
+        twitch.ensure_token()?;
+        Ok(twitch)
+    }
+
+    /// Attach application info to this client, sent as a `User-Agent` header
+    /// on every request so that Twitch can identify the calling application.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - the application info to send
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::{AppInfo, Twitch};
+    /// let twitch = Twitch::new("abc").with_app_info(AppInfo {
+    ///     name: "my_app".to_owned(),
+    ///     version: Some("1.2.3".to_owned()),
+    ///     url: None,
+    /// });
+    /// ```
+    pub fn with_app_info(mut self, info: AppInfo) -> Self {
+        self.app_info = Some(info);
+        self
+    }
+
     /// Get the base REST API URL.
     fn base_url(&self) -> String {
         #[cfg(not(test))]
@@ -62,14 +199,88 @@ impl Twitch {
         return mockito::server_url();
     }
 
+    /// Get the base OAuth2 URL.
+    fn auth_url(&self) -> String {
+        #[cfg(not(test))]
+        return "https://id.twitch.tv/oauth2/token".to_owned();
+        #[cfg(test)]
+        return format!("{}/oauth2/token", mockito::server_url());
+    }
+
+    /// Perform the client-credentials flow and return the resulting app access token.
+    ///
+    /// This doesn't touch the cached token itself; callers are expected to
+    /// hold `self.token`'s lock for the whole check-and-refresh so that
+    /// concurrent callers can't race into redundant refreshes.
+    fn fetch_token(&self) -> Result<CachedToken> {
+        let client_secret = self
+            .client_secret
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No client secret set; use Twitch::with_secret"))?;
+        debug!("Refreshing app access token");
+        let mut resp = self
+            .client
+            .post(&self.auth_url())
+            .query(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", client_secret.as_str()),
+                ("grant_type", "client_credentials"),
+            ])
+            .send()?;
+        if !resp.status().is_success() {
+            anyhow::bail!(
+                "Received status code {} from API while fetching an app access token",
+                resp.status()
+            );
+        }
+        let token: TokenResponse = resp.json()?;
+        Ok(CachedToken {
+            access_token: token.access_token,
+            expires_at: Instant::now() + Duration::from_secs(token.expires_in),
+        })
+    }
+
+    /// Make sure a non-expired app access token is cached, refreshing it if needed.
+    ///
+    /// The expiry check and the refresh happen under a single lock
+    /// acquisition so that two callers racing an expired token can't both
+    /// decide a refresh is needed and both fire a redundant request.
+    fn ensure_token(&self) -> Result<()> {
+        if self.client_secret.is_none() {
+            return Ok(());
+        }
+        let mut token = self.token.lock().unwrap();
+        let needs_refresh = match &*token {
+            Some(token) => Instant::now() >= token.expires_at,
+            None => true,
+        };
+        if needs_refresh {
+            *token = Some(self.fetch_token()?);
+        }
+        Ok(())
+    }
+
     /// Populate a map of the required headers.
-    fn get_headers(&self) -> HeaderMap {
+    fn get_headers(&self) -> Result<HeaderMap> {
+        self.ensure_token()?;
         let mut map = HeaderMap::new();
         map.insert(
             HeaderName::from_str("client-id").unwrap(),
             HeaderValue::from_bytes(self.client_id.as_bytes()).unwrap(),
         );
-        map
+        if let Some(token) = &*self.token.lock().unwrap() {
+            map.insert(
+                HeaderName::from_str("authorization").unwrap(),
+                HeaderValue::from_bytes(format!("Bearer {}", token.access_token).as_bytes())?,
+            );
+        }
+        if let Some(app_info) = &self.app_info {
+            map.insert(
+                HeaderName::from_str("user-agent").unwrap(),
+                HeaderValue::from_bytes(app_info.to_user_agent().as_bytes())?,
+            );
+        }
+        Ok(map)
     }
 
     /// Query an endpoint.
@@ -110,7 +321,7 @@ impl Twitch {
                 Method::from_str(method)?,
                 &format!("{}/{}", self.base_url(), endpoint),
             )
-            .headers(self.get_headers());
+            .headers(self.get_headers()?);
         let req = match query {
             Some(q) => req.query(&q),
             None => req,
@@ -129,6 +340,31 @@ impl Twitch {
         Ok(resp)
     }
 
+    /// Look up items by a repeated id-like query param (e.g. `id` or
+    /// `login`), chunking into groups of at most 100 per Twitch's per-request
+    /// cap and issuing one non-paginated `query` call per chunk.
+    ///
+    /// This is for endpoints where the query param selects specific items
+    /// rather than paging through a result set, so `query_paginated`'s
+    /// cursor-based pagination doesn't apply.
+    fn query_by_ids<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        key: &str,
+        ids: &[&str],
+    ) -> Result<Vec<T>> {
+        let mut items = vec![];
+        for chunk in ids.chunks(100) {
+            let query: Vec<(&str, &str)> = chunk.iter().map(|id| (key, *id)).collect();
+            let raw_data: Value = self.query("GET", endpoint, Some(&query))?;
+            let raw_items = raw_data["data"].as_array().cloned().unwrap_or_default();
+            for raw_item in raw_items {
+                items.push(serde_json::from_value(raw_item)?);
+            }
+        }
+        Ok(items)
+    }
+
     /// Query a paginated endpoint.
     ///
     /// This is mostly used as an internal method but can be used
@@ -145,6 +381,10 @@ impl Twitch {
     /// provides, use the simpler `query` function provided by this library
     /// instead, optionally passing in "first"/"after"/etc. query params.
     ///
+    /// If the API runs out of results before `count` is reached, this stops
+    /// early instead of erroring; use `query_paginated_page` if you need to
+    /// know whether that happened.
+    ///
     /// # Arguments
     ///
     /// * `method` - HTTP method string
@@ -172,6 +412,38 @@ impl Twitch {
         endpoint_maximum: u64,
         count: u64,
     ) -> Result<Vec<T>> {
+        let page = self.query_paginated_page(method, endpoint, query, endpoint_maximum, count)?;
+        Ok(page.items)
+    }
+
+    /// Same as `query_paginated`, but also returns the pagination cursor and
+    /// the `total` size of the result set that Twitch reports on many list
+    /// endpoints.
+    ///
+    /// A missing or null `pagination.cursor` is treated as "no more
+    /// results" rather than an error, so the returned `Page` may have fewer
+    /// items than `count` requested; check `cursor` to tell whether that's
+    /// because the result set was exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method string
+    /// * `endpoint` - API endpoint (don't include a leading slash)
+    /// * `query` - optional query params to include
+    /// * `endpoint_maximum` - how many items the endpoint returns per request
+    /// * `count` - how many items to get
+    ///
+    /// # Types
+    ///
+    /// * `T` - a struct to deserialize the individual data items
+    pub fn query_paginated_page<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query: Option<&[(&str, &str)]>,
+        endpoint_maximum: u64,
+        count: u64,
+    ) -> Result<Page<T>> {
         let pages_to_request = (count as f64 / endpoint_maximum as f64).ceil() as u64;
         debug!("Starting paginated: method = {}, endpoint = {}, query = {:?}, endpoint_maximum = {}, count = {}, pages_to_request = {}",
             method,
@@ -182,7 +454,8 @@ impl Twitch {
             pages_to_request
         );
         let mut items = vec![];
-        let mut after = String::new();
+        let mut after: Option<String> = None;
+        let mut total = None;
         for i in 0..pages_to_request {
             let req_count = format!(
                 "{}",
@@ -199,17 +472,102 @@ impl Twitch {
                 }
             }
             all_query.push(("first", &req_count));
-            all_query.push(("after", &after));
-            let raw_data: Value = self.query(method, endpoint, Some(&all_query))?;
-            after = raw_data["pagination"]["cursor"]
-                .as_str()
-                .unwrap()
-                .to_owned();
-            let raw_data_items = serde_json::to_string(raw_data["data"].as_array().unwrap())?;
-            let mut data_items: Vec<T> = serde_json::from_str(&raw_data_items)?;
-            items.append(&mut data_items);
+            if let Some(after) = &after {
+                all_query.push(("after", after));
+            }
+            let page = self.fetch_page::<T>(method, endpoint, &all_query)?;
+            after = page.cursor;
+            total = page.total.or(total);
+            let got_items = !page.items.is_empty();
+            items.extend(page.items);
+            if after.is_none() || !got_items {
+                break;
+            }
+        }
+        Ok(Page {
+            items,
+            cursor: after,
+            total,
+        })
+    }
+
+    /// Issue a single page request and parse out its items, cursor, and total.
+    ///
+    /// Shared by `query_paginated_page` and `PaginatedStream` so the two
+    /// pagination paths parse Twitch's response envelope the same way.
+    fn fetch_page<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Page<T>> {
+        let raw_data: Value = self.query(method, endpoint, Some(query))?;
+        let cursor = raw_data["pagination"]["cursor"].as_str().map(|s| s.to_owned());
+        let total = raw_data["total"].as_i64();
+        let raw_items = raw_data["data"].as_array().cloned().unwrap_or_default();
+        let mut items = Vec::with_capacity(raw_items.len());
+        for raw_item in raw_items {
+            items.push(serde_json::from_value(raw_item)?);
+        }
+        Ok(Page {
+            items,
+            cursor,
+            total,
+        })
+    }
+
+    /// Lazily stream a paginated endpoint, one page at a time.
+    ///
+    /// Unlike `query_paginated`, this doesn't require committing to a fixed
+    /// item count up front: it fetches a page, yields its items, and only
+    /// fetches the next page once the consumer asks for more. Iteration
+    /// stops once the API response carries no pagination cursor, so this is
+    /// safe to combine with `Iterator::take` or `Iterator::filter` without
+    /// over-fetching.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method string
+    /// * `endpoint` - API endpoint (don't include a leading slash)
+    /// * `query` - optional query params to include
+    /// * `endpoint_maximum` - how many items the endpoint returns per request
+    ///
+    /// # Types
+    ///
+    /// * `T` - a struct to deserialize the individual data items
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::{Twitch, models::streams::StreamListItem};
+    /// # let twitch = Twitch::new("abc");
+    /// let first_five: Vec<StreamListItem> = twitch
+    ///     .stream("GET", "streams", None, 100)
+    ///     .take(5)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// ```
+    pub fn stream<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query: Option<&[(&str, &str)]>,
+        endpoint_maximum: u64,
+    ) -> PaginatedStream<T> {
+        PaginatedStream {
+            twitch: self,
+            method: method.to_owned(),
+            endpoint: endpoint.to_owned(),
+            query: query
+                .unwrap_or(&[])
+                .iter()
+                .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+                .collect(),
+            endpoint_maximum,
+            after: None,
+            done: false,
+            buffer: VecDeque::new(),
         }
-        Ok(items)
     }
 
     /// Get the top streams.
@@ -229,11 +587,168 @@ impl Twitch {
         let data = self.query_paginated("GET", "streams", None, 100, count)?;
         Ok(data)
     }
+
+    /// Get videos by id.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - the video ids to look up
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::Twitch;
+    /// # let twitch = Twitch::new("abc");
+    /// let videos = twitch.get_videos(&["123456789"]).unwrap();
+    /// ```
+    pub fn get_videos(&self, ids: &[&str]) -> Result<Vec<models::videos::Video>> {
+        self.query_by_ids("videos", "id", ids)
+    }
+
+    /// Get the most recent videos for a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_id` - the game id to look up videos for
+    /// * `count` - how many to retrieve
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::Twitch;
+    /// # let twitch = Twitch::new("abc");
+    /// let videos = twitch.get_videos_by_game("33214", 25).unwrap();
+    /// ```
+    pub fn get_videos_by_game(
+        &self,
+        game_id: &str,
+        count: u64,
+    ) -> Result<Vec<models::videos::Video>> {
+        let query = [("game_id", game_id)];
+        let data = self.query_paginated("GET", "videos", Some(&query), 100, count)?;
+        Ok(data)
+    }
+
+    /// Get the top clips for a broadcaster.
+    ///
+    /// # Arguments
+    ///
+    /// * `broadcaster_id` - the broadcaster id to look up clips for
+    /// * `count` - how many to retrieve
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::Twitch;
+    /// # let twitch = Twitch::new("abc");
+    /// let clips = twitch.get_clips("44322889", 25).unwrap();
+    /// ```
+    pub fn get_clips(&self, broadcaster_id: &str, count: u64) -> Result<Vec<models::clips::Clip>> {
+        let query = [("broadcaster_id", broadcaster_id)];
+        let data = self.query_paginated("GET", "clips", Some(&query), 100, count)?;
+        Ok(data)
+    }
+
+    /// Get users by login name.
+    ///
+    /// # Arguments
+    ///
+    /// * `logins` - the login names to look up
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::Twitch;
+    /// # let twitch = Twitch::new("abc");
+    /// let users = twitch.get_users(&["twitchdev"]).unwrap();
+    /// ```
+    pub fn get_users(&self, logins: &[&str]) -> Result<Vec<models::users::User>> {
+        self.query_by_ids("users", "login", logins)
+    }
+
+    /// Get games by id.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - the game ids to look up
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use twitch_wrapper::Twitch;
+    /// # let twitch = Twitch::new("abc");
+    /// let games = twitch.get_games(&["33214"]).unwrap();
+    /// ```
+    pub fn get_games(&self, ids: &[&str]) -> Result<Vec<models::games::Game>> {
+        self.query_by_ids("games", "id", ids)
+    }
+}
+
+/// Iterator returned by `Twitch::stream`.
+///
+/// See that method's documentation for details.
+pub struct PaginatedStream<'t, T> {
+    /// the client to issue requests through
+    twitch: &'t Twitch,
+    /// HTTP method string
+    method: String,
+    /// API endpoint
+    endpoint: String,
+    /// query params to include on every page request
+    query: Vec<(String, String)>,
+    /// how many items to request per page
+    endpoint_maximum: u64,
+    /// the cursor to resume from, if any
+    after: Option<String>,
+    /// whether no more pages will be fetched: the API returned no cursor or
+    /// an empty page, or a page request failed
+    done: bool,
+    /// items fetched but not yet yielded
+    buffer: VecDeque<T>,
+}
+
+impl<'t, T: DeserializeOwned> PaginatedStream<'t, T> {
+    /// Fetch the next page and buffer its items.
+    fn fetch_next_page(&mut self) -> Result<()> {
+        let req_count = format!("{}", self.endpoint_maximum);
+        let mut all_query: Vec<(&str, &str)> = self
+            .query
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        all_query.push(("first", &req_count));
+        if let Some(after) = &self.after {
+            all_query.push(("after", after));
+        }
+        let page: Page<T> = self
+            .twitch
+            .fetch_page(&self.method, &self.endpoint, &all_query)?;
+        self.after = page.cursor;
+        if self.after.is_none() || page.items.is_empty() {
+            self.done = true;
+        }
+        self.buffer.extend(page.items);
+        Ok(())
+    }
+}
+
+impl<'t, T: DeserializeOwned> Iterator for PaginatedStream<'t, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && !self.done {
+            if let Err(e) = self.fetch_next_page() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Twitch;
+    use super::{AppInfo, Page, Twitch};
     use mockito::mock;
     use serde::Deserialize;
 
@@ -243,6 +758,30 @@ mod tests {
         value: i64,
     }
 
+    #[test]
+    fn test_with_secret() {
+        let _m = mock("POST", "/oauth2/token")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("client_id".into(), "abc".into()),
+                mockito::Matcher::UrlEncoded("client_secret".into(), "def".into()),
+                mockito::Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+            ]))
+            .with_body(r#"{"access_token": "xyz", "expires_in": 3600, "token_type": "bearer"}"#)
+            .create();
+        let t = Twitch::with_secret("abc", "def").unwrap();
+
+        let _m2 = mock("GET", "/endpoint")
+            .match_header("client-id", "abc")
+            .match_header("authorization", "Bearer xyz")
+            .with_body(r#"{"message": "hello world", "value": -100}"#)
+            .create();
+        let resp: SampleResponse = t.query("GET", "endpoint", None).unwrap();
+
+        assert_eq!(resp.message, "hello world");
+        _m.assert();
+        _m2.assert();
+    }
+
     #[test]
     fn test_query() {
         let t = Twitch::new("abc");
@@ -257,10 +796,71 @@ mod tests {
         _m.assert();
     }
 
+    #[test]
+    fn test_query_by_ids_chunks_over_100() {
+        let t = Twitch::new("abc");
+        let ids: Vec<String> = (0..150).map(|i| format!("id{}", i)).collect();
+        let id_refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+
+        let first_chunk_items: Vec<String> = (0..100)
+            .map(|i| format!(r#"{{"message": "item {}", "value": {}}}"#, i, i))
+            .collect();
+        let second_chunk_items: Vec<String> = (100..150)
+            .map(|i| format!(r#"{{"message": "item {}", "value": {}}}"#, i, i))
+            .collect();
+
+        let _m1 = mock("GET", "/endpoint")
+            .match_header("client-id", "abc")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("id".into(), "id0".into()),
+                mockito::Matcher::UrlEncoded("id".into(), "id99".into()),
+            ]))
+            .with_body(format!(r#"{{"data": [{}]}}"#, first_chunk_items.join(",")))
+            .create();
+        let _m2 = mock("GET", "/endpoint")
+            .match_header("client-id", "abc")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("id".into(), "id100".into()),
+                mockito::Matcher::UrlEncoded("id".into(), "id149".into()),
+            ]))
+            .with_body(format!(
+                r#"{{"data": [{}]}}"#,
+                second_chunk_items.join(",")
+            ))
+            .create();
+
+        let resp: Vec<SampleResponse> = t.query_by_ids("endpoint", "id", &id_refs).unwrap();
+
+        assert_eq!(resp.len(), 150);
+        assert_eq!(resp[0].message, "item 0");
+        assert_eq!(resp[99].message, "item 99");
+        assert_eq!(resp[149].message, "item 149");
+        _m1.assert();
+        _m2.assert();
+    }
+
+    #[test]
+    fn test_with_app_info() {
+        let t = Twitch::new("abc").with_app_info(AppInfo {
+            name: "my_app".to_owned(),
+            version: Some("1.2.3".to_owned()),
+            url: Some("https://example.com".to_owned()),
+        });
+        let _m = mock("GET", "/endpoint")
+            .match_header("client-id", "abc")
+            .match_header("user-agent", "my_app/1.2.3 (https://example.com)")
+            .with_body(r#"{"message": "hello world", "value": -100}"#)
+            .create();
+        let resp: SampleResponse = t.query("GET", "endpoint", None).unwrap();
+
+        assert_eq!(resp.message, "hello world");
+        _m.assert();
+    }
+
     #[test]
     fn test_query_paginated() {
         let t = Twitch::new("abc");
-        let _m1 = mock("GET", "/endpoint?first=2&after=")
+        let _m1 = mock("GET", "/endpoint?first=2")
             .match_header("client-id", "abc")
             .with_body(r#"{"data": [ {"message": "first call", "value": 1}, {"message": "second call", "value": 2} ], "pagination": { "cursor": "abc" }}"#)
             .create();
@@ -304,4 +904,54 @@ mod tests {
         _m2.assert();
         _m3.assert();
     }
+
+    #[test]
+    fn test_query_paginated_exhausted_cursor() {
+        let t = Twitch::new("abc");
+        let _m1 = mock("GET", "/endpoint?first=3")
+            .match_header("client-id", "abc")
+            .with_body(r#"{"data": [ {"message": "only call", "value": 1} ], "pagination": {}, "total": 1}"#)
+            .create();
+        let page: Page<SampleResponse> = t
+            .query_paginated_page("GET", "endpoint", None, 3, 10)
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.cursor, None);
+        assert_eq!(page.total, Some(1));
+        _m1.assert();
+    }
+
+    #[test]
+    fn test_stream() {
+        let t = Twitch::new("abc");
+        let _m1 = mock("GET", "/endpoint?first=2")
+            .match_header("client-id", "abc")
+            .with_body(r#"{"data": [ {"message": "first call", "value": 1}, {"message": "second call", "value": 2} ], "pagination": { "cursor": "abc" }}"#)
+            .create();
+        let _m2 = mock("GET", "/endpoint?first=2&after=abc")
+            .match_header("client-id", "abc")
+            .with_body(r#"{"data": [ {"message": "third call", "value": 3} ], "pagination": {}}"#)
+            .create();
+        let resp: Vec<SampleResponse> = t
+            .stream::<SampleResponse>("GET", "endpoint", None, 2)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected: Vec<SampleResponse> = vec![
+            SampleResponse {
+                message: String::from("first call"),
+                value: 1,
+            },
+            SampleResponse {
+                message: String::from("second call"),
+                value: 2,
+            },
+            SampleResponse {
+                message: String::from("third call"),
+                value: 3,
+            },
+        ];
+        assert_eq!(expected, resp);
+        _m1.assert();
+        _m2.assert();
+    }
 }
@@ -0,0 +1,48 @@
+//! Models relating to endpoints dealing with clips.
+
+use super::Pagination;
+use serde::{Deserialize, Serialize};
+
+/// An item in the list of clips.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Clip {
+    /// 'id' field
+    pub id: String,
+    /// 'url' field
+    pub url: String,
+    /// 'embed_url' field
+    pub embed_url: String,
+    /// 'broadcaster_id' field
+    pub broadcaster_id: String,
+    /// 'broadcaster_name' field
+    pub broadcaster_name: String,
+    /// 'creator_id' field
+    pub creator_id: String,
+    /// 'creator_name' field
+    pub creator_name: String,
+    /// 'video_id' field
+    pub video_id: String,
+    /// 'game_id' field
+    pub game_id: String,
+    /// 'language' field
+    pub language: String,
+    /// 'title' field
+    pub title: String,
+    /// 'view_count' field
+    pub view_count: u64,
+    /// 'created_at' field
+    pub created_at: String,
+    /// 'thumbnail_url' field
+    pub thumbnail_url: String,
+    /// 'duration' field
+    pub duration: f64,
+}
+
+/// The list of clips.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ClipList {
+    /// 'data' field
+    pub data: Vec<Clip>,
+    /// 'pagination' field
+    pub pagination: Pagination,
+}
@@ -0,0 +1,24 @@
+//! Models relating to endpoints dealing with games.
+
+use super::Pagination;
+use serde::{Deserialize, Serialize};
+
+/// An item in the list of games.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Game {
+    /// 'id' field
+    pub id: String,
+    /// 'name' field
+    pub name: String,
+    /// 'box_art_url' field
+    pub box_art_url: String,
+}
+
+/// The list of games.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GameList {
+    /// 'data' field
+    pub data: Vec<Game>,
+    /// 'pagination' field
+    pub pagination: Pagination,
+}
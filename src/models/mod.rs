@@ -2,7 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod clips;
+pub mod games;
 pub mod streams;
+pub mod users;
+pub mod videos;
 
 /// Struct to hold the pagination information.
 #[derive(Debug, Deserialize, Serialize)]
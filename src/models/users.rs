@@ -0,0 +1,41 @@
+//! Models relating to endpoints dealing with users.
+
+use super::Pagination;
+use serde::{Deserialize, Serialize};
+
+/// An item in the list of users.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct User {
+    /// 'id' field
+    pub id: String,
+    /// 'login' field
+    pub login: String,
+    /// 'display_name' field
+    pub display_name: String,
+    #[serde(rename = "type")]
+    /// 'type_' field (gets automatically renamed from 'type')
+    pub type_: String,
+    /// 'broadcaster_type' field
+    pub broadcaster_type: String,
+    /// 'description' field
+    pub description: String,
+    /// 'profile_image_url' field
+    pub profile_image_url: String,
+    /// 'offline_image_url' field
+    pub offline_image_url: String,
+    /// 'view_count' field
+    pub view_count: u64,
+    /// 'email' field (only present with the `user:read:email` scope)
+    pub email: Option<String>,
+    /// 'created_at' field
+    pub created_at: String,
+}
+
+/// The list of users.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserList {
+    /// 'data' field
+    pub data: Vec<User>,
+    /// 'pagination' field
+    pub pagination: Pagination,
+}
@@ -0,0 +1,41 @@
+//! Models relating to endpoints dealing with videos.
+
+use super::Pagination;
+use serde::{Deserialize, Serialize};
+
+/// An item in the list of videos.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Video {
+    /// 'id' field
+    pub id: String,
+    /// 'user_id' field
+    pub user_id: String,
+    /// 'title' field
+    pub title: String,
+    /// 'description' field
+    pub description: String,
+    /// 'created_at' field
+    pub created_at: String,
+    /// 'url' field
+    pub url: String,
+    /// 'thumbnail_url' field
+    pub thumbnail_url: String,
+    /// 'view_count' field
+    pub view_count: u64,
+    /// 'duration' field
+    pub duration: String,
+    #[serde(rename = "type")]
+    /// 'type_' field (gets automatically renamed from 'type')
+    pub type_: String,
+    /// 'language' field
+    pub language: String,
+}
+
+/// The list of videos.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct VideoList {
+    /// 'data' field
+    pub data: Vec<Video>,
+    /// 'pagination' field
+    pub pagination: Pagination,
+}